@@ -0,0 +1,217 @@
+//! A variant of [`Pool`] that stores elements behind a [`Box`], for types where moving a
+//! pointer in and out of the backing `Vec` is cheaper than moving `T` itself.
+
+use parking_lot::Mutex;
+use std::mem::{forget, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+/// Like [`Pool`](crate::Pool), but the backing stack holds `Box<T>` instead of `T`.
+///
+/// Returning and pulling an object only ever moves a pointer-sized `Box`, rather than
+/// memcpy'ing a potentially large `T`, which roughly doubles push/pop throughput for big
+/// payloads at the cost of one allocation per object (paid once, when the object is created
+/// by `new` or by a `pull` fallback).
+pub struct BoxedPool<T> {
+    objects: Mutex<Vec<Box<T>>>,
+}
+
+impl<T> BoxedPool<T> {
+    #[inline]
+    pub fn new<F>(cap: usize, mut init: F) -> BoxedPool<T>
+    where
+        F: FnMut() -> T,
+    {
+        BoxedPool {
+            objects: Mutex::new((0..cap).map(|_| Box::new(init())).collect()),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_vec(v: Vec<Box<T>>) -> BoxedPool<T> {
+        BoxedPool {
+            objects: Mutex::new(v),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.objects.lock().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.objects.lock().is_empty()
+    }
+
+    #[inline]
+    pub fn try_pull(&self) -> Option<BoxedReusable<'_, T>> {
+        self.objects
+            .lock()
+            .pop()
+            .map(|data| BoxedReusable::new(self, data))
+    }
+
+    #[inline]
+    pub fn pull<F: Fn() -> T>(&self, fallback: F) -> BoxedReusable<'_, T> {
+        self.try_pull()
+            .unwrap_or_else(|| BoxedReusable::new(self, Box::new(fallback())))
+    }
+
+    /// Like `try_pull`, but returns an owned reusable wrapper, see
+    /// [`Pool::try_pull_owned`](crate::Pool::try_pull_owned).
+    #[inline]
+    pub fn try_pull_owned(self: &Arc<Self>) -> Option<BoxedReusableOwned<T>> {
+        self.objects
+            .lock()
+            .pop()
+            .map(|data| BoxedReusableOwned::new(self.clone(), data))
+    }
+
+    /// Like `pull`, but returns an owned reusable wrapper, see
+    /// [`Pool::pull_owned`](crate::Pool::pull_owned).
+    #[inline]
+    pub fn pull_owned<F: Fn() -> T>(self: &Arc<Self>, fallback: F) -> BoxedReusableOwned<T> {
+        self.try_pull_owned()
+            .unwrap_or_else(|| BoxedReusableOwned::new(self.clone(), Box::new(fallback())))
+    }
+
+    #[inline]
+    pub fn attach(&self, t: Box<T>) {
+        self.objects.lock().push(t);
+    }
+}
+
+pub struct BoxedReusable<'a, T> {
+    pool: &'a BoxedPool<T>,
+    data: ManuallyDrop<Box<T>>,
+}
+
+impl<'a, T> BoxedReusable<'a, T> {
+    #[inline]
+    pub fn new(pool: &'a BoxedPool<T>, t: Box<T>) -> Self {
+        Self {
+            pool,
+            data: ManuallyDrop::new(t),
+        }
+    }
+
+    #[inline]
+    pub fn detach(mut self) -> (&'a BoxedPool<T>, Box<T>) {
+        let ret = unsafe { (self.pool, self.take()) };
+        forget(self);
+        ret
+    }
+
+    unsafe fn take(&mut self) -> Box<T> {
+        ManuallyDrop::take(&mut self.data)
+    }
+}
+
+impl<'a, T> Deref for BoxedReusable<'a, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<'a, T> DerefMut for BoxedReusable<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+impl<'a, T> Drop for BoxedReusable<'a, T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { self.pool.attach(self.take()) }
+    }
+}
+
+pub struct BoxedReusableOwned<T> {
+    pool: ManuallyDrop<Arc<BoxedPool<T>>>,
+    data: ManuallyDrop<Box<T>>,
+}
+
+impl<T> BoxedReusableOwned<T> {
+    #[inline]
+    pub fn new(pool: Arc<BoxedPool<T>>, t: Box<T>) -> Self {
+        Self {
+            pool: ManuallyDrop::new(pool),
+            data: ManuallyDrop::new(t),
+        }
+    }
+
+    #[inline]
+    pub fn detach(mut self) -> (Arc<BoxedPool<T>>, Box<T>) {
+        let ret = unsafe { self.take() };
+        forget(self);
+        ret
+    }
+
+    unsafe fn take(&mut self) -> (Arc<BoxedPool<T>>, Box<T>) {
+        (
+            ManuallyDrop::take(&mut self.pool),
+            ManuallyDrop::take(&mut self.data),
+        )
+    }
+}
+
+impl<T> Deref for BoxedReusableOwned<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T> DerefMut for BoxedReusableOwned<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+impl<T> Drop for BoxedReusableOwned<T> {
+    #[inline]
+    fn drop(&mut self) {
+        let (pool, data) = unsafe { self.take() };
+        pool.attach(data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoxedPool;
+    use std::mem::drop;
+
+    #[test]
+    fn pull() {
+        let pool = BoxedPool::<Vec<u8>>::new(1, Vec::new);
+
+        let object1 = pool.try_pull();
+        let object2 = pool.try_pull();
+        let object3 = pool.pull(Vec::new);
+
+        assert!(object1.is_some());
+        assert!(object2.is_none());
+        drop(object1);
+        drop(object2);
+        drop(object3);
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[test]
+    fn detach_then_attach() {
+        let pool = BoxedPool::new(1, Vec::new);
+        let (pool, mut object) = pool.try_pull().unwrap().detach();
+        object.push(1);
+        pool.attach(object);
+        assert_eq!(pool.try_pull().unwrap()[0], 1);
+    }
+}
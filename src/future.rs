@@ -0,0 +1,228 @@
+//! Async waiting on a [`Pool`], gated behind the `async` feature.
+
+use crate::{Pool, Reusable, ReusableOwned};
+use futures_core::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+#[inline]
+fn next_waiter_id() -> u64 {
+    static COUNTER: AtomicU64 = AtomicU64::new(1);
+    COUNTER.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Tries to pull from `pool`, registering `cx`'s waker in `pool.wakers` (keyed by
+/// `*registered`, which persists across polls of the same future/stream) if none is
+/// available yet. Shared by [`PullFuture::poll`] and [`PoolStream::poll_next`].
+fn poll_pull<'p, T>(
+    pool: &'p Pool<T>,
+    registered: &mut Option<u64>,
+    cx: &mut Context<'_>,
+) -> Poll<Reusable<'p, T>> {
+    if let Some(reusable) = pool.try_pull() {
+        return Poll::Ready(reusable);
+    }
+    let mut wakers = pool.wakers.lock();
+    if let Some((_, waker)) = registered.and_then(|id| wakers.iter_mut().find(|(i, _)| *i == id))
+    {
+        if !waker.will_wake(cx.waker()) {
+            waker.clone_from(cx.waker());
+        }
+    } else {
+        let id = next_waiter_id();
+        wakers.push((id, cx.waker().clone()));
+        *registered = Some(id);
+    }
+    drop(wakers);
+    // An object may have been attached between the `try_pull` above and registering the
+    // waker; check once more so we don't wait forever.
+    pool.try_pull().map_or(Poll::Pending, Poll::Ready)
+}
+
+/// Removes this waiter's entry from `pool.wakers`, if it ever registered one. Shared by
+/// [`PullFuture`] and [`PoolStream`]'s `Drop` impls so a waiter that's dropped while still
+/// pending doesn't leave a stale waker behind for [`Pool::wake_one`](crate::Pool::wake_one)
+/// to find.
+fn deregister_waiter<T>(pool: &Pool<T>, registered: &mut Option<u64>) {
+    if let Some(id) = registered.take() {
+        let mut wakers = pool.wakers.lock();
+        if let Some(pos) = wakers.iter().position(|(i, _)| *i == id) {
+            wakers.remove(pos);
+        }
+    }
+}
+
+pub(crate) struct PullFuture<'a, T> {
+    pool: &'a Pool<T>,
+    registered: Option<u64>,
+}
+
+impl<'a, T> PullFuture<'a, T> {
+    #[inline]
+    pub(crate) fn new(pool: &'a Pool<T>) -> Self {
+        Self {
+            pool,
+            registered: None,
+        }
+    }
+}
+
+impl<'a, T> Future for PullFuture<'a, T> {
+    type Output = Reusable<'a, T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        poll_pull(this.pool, &mut this.registered, cx)
+    }
+}
+
+impl<'a, T> Drop for PullFuture<'a, T> {
+    fn drop(&mut self) {
+        deregister_waiter(self.pool, &mut self.registered);
+    }
+}
+
+/// Yields a [`ReusableOwned<T>`] each time one becomes available in the pool.
+///
+/// Obtained with [`Pool::stream`].
+pub struct PoolStream<T> {
+    pool: Arc<Pool<T>>,
+    registered: Option<u64>,
+}
+
+impl<T> PoolStream<T> {
+    #[inline]
+    pub(crate) fn new(pool: Arc<Pool<T>>) -> Self {
+        Self {
+            pool,
+            registered: None,
+        }
+    }
+}
+
+impl<T> Stream for PoolStream<T> {
+    type Item = ReusableOwned<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        match poll_pull(&this.pool, &mut this.registered, cx) {
+            Poll::Ready(reusable) => {
+                let (_, data) = reusable.detach();
+                Poll::Ready(Some(ReusableOwned::new(this.pool.clone(), data)))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<T> Drop for PoolStream<T> {
+    fn drop(&mut self) {
+        deregister_waiter(&self.pool, &mut self.registered);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Pool;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+    fn noop_waker() -> Waker {
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+    }
+
+    fn poll_once<F: Future>(fut: Pin<&mut F>) -> Poll<F::Output> {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        fut.poll(&mut cx)
+    }
+
+    #[test]
+    fn pull_async_ready_when_object_available() {
+        let pool = Pool::new(1, Vec::<u8>::new);
+        let mut fut = Box::pin(pool.pull_async());
+        match poll_once(fut.as_mut()) {
+            Poll::Ready(_) => {}
+            Poll::Pending => panic!("expected an object to be immediately available"),
+        };
+    }
+
+    #[test]
+    fn pull_async_wakes_waiter_on_attach() {
+        let pool = Arc::new(Pool::new(1, Vec::<u8>::new));
+        let held = pool.try_pull().unwrap();
+
+        let mut fut = Box::pin(pool.pull_async());
+        assert!(matches!(poll_once(fut.as_mut()), Poll::Pending));
+
+        drop(held);
+        assert!(matches!(poll_once(fut.as_mut()), Poll::Ready(_)));
+    }
+
+    #[test]
+    fn pull_async_does_not_leak_duplicate_wakers() {
+        let pool = Arc::new(Pool::new(1, Vec::<u8>::new));
+        let held = pool.try_pull().unwrap();
+
+        let mut fut = Box::pin(pool.pull_async());
+        for _ in 0..8 {
+            assert!(matches!(poll_once(fut.as_mut()), Poll::Pending));
+        }
+        assert_eq!(pool.wakers.lock().len(), 1);
+
+        drop(held);
+        assert!(matches!(poll_once(fut.as_mut()), Poll::Ready(_)));
+    }
+
+    #[test]
+    fn pull_async_fast_slot_is_visible_to_a_different_thread() {
+        let pool = Arc::new(Pool::new(0, Vec::<u8>::new));
+        // This thread becomes the fast-path owner and holds the pool's only object.
+        let held = pool.pull(Vec::new);
+
+        let (registered_tx, registered_rx) = std::sync::mpsc::channel();
+        let (returned_tx, returned_rx) = std::sync::mpsc::channel();
+        let waiter_pool = pool.clone();
+        let waiter = std::thread::spawn(move || {
+            let mut fut = Box::pin(waiter_pool.pull_async());
+            assert!(matches!(poll_once(fut.as_mut()), Poll::Pending));
+            registered_tx.send(()).unwrap();
+            returned_rx.recv().unwrap();
+            matches!(poll_once(fut.as_mut()), Poll::Ready(_))
+        });
+
+        registered_rx.recv().unwrap();
+        // Dropping `held` on the owner thread returns the object through `fast_slot`; the
+        // waiter thread above is never the owner, so it must fall back to `fast_slot`
+        // itself instead of waiting forever.
+        drop(held);
+        returned_tx.send(()).unwrap();
+
+        assert!(waiter.join().unwrap());
+    }
+
+    #[test]
+    fn dropping_a_pending_future_deregisters_its_waker() {
+        let pool = Arc::new(Pool::new(1, Vec::<u8>::new));
+        let held = pool.try_pull().unwrap();
+
+        let mut fut = Box::pin(pool.pull_async());
+        assert!(matches!(poll_once(fut.as_mut()), Poll::Pending));
+        assert_eq!(pool.wakers.lock().len(), 1);
+
+        drop(fut);
+        assert_eq!(pool.wakers.lock().len(), 0);
+
+        drop(held);
+    }
+}
@@ -73,25 +73,108 @@
 //! You may want to call `object.reset()` or  `object.clear()`
 //! or any other equivalent for the object that you are using, after pulling from the pool
 //!
+//! If resetting manually is error-prone for your use case, implement [`Recyclable`] for
+//! your object and build the pool with [`Pool::new_recyclable`] instead; the object will
+//! be reset for you whenever it is returned to the pool.
+//!
+//! # Async
+//!
+//! With the `async` feature enabled, [`Pool::pull_async`]/[`Pool::pull_owned_async`] wait
+//! for an object to be returned instead of falling back to allocation, and [`Pool::stream`]
+//! yields a [`ReusableOwned`] each time one becomes available. This is useful for pools that
+//! cap the total number of live objects rather than growing to meet demand.
+//!
+//! # Large objects
+//!
+//! `Pool<T>` moves `T` in and out of its backing stack on every pull/return, which is cheap
+//! for small types but can dominate for large ones. [`BoxedPool<T>`] stores `Box<T>`
+//! instead, so pulling and returning only ever moves a pointer.
+//!
+//! # Single-threaded use
+//!
+//! `Pool<T>` requires `T: Send` and pays for a `Mutex` even when it's only ever touched
+//! from one thread. [`LocalPool<T>`] is a `RefCell`-backed equivalent with the same
+//! `try_pull`/`pull`/`attach`/`detach` surface, usable with `!Send`/`!Sync` `T`.
+//!
 //! [`std::sync::Arc`]: https://doc.rust-lang.org/stable/std/sync/struct.Arc.html
 
 #![warn(clippy::all, clippy::pedantic)]
 
 use parking_lot::Mutex;
+use std::cell::UnsafeCell;
 use std::iter::FromIterator;
 use std::mem::{forget, ManuallyDrop};
 use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 
+thread_local! {
+    static THREAD_ID: u64 = next_thread_id();
+}
+
+#[inline]
+fn next_thread_id() -> u64 {
+    static COUNTER: AtomicU64 = AtomicU64::new(1);
+    COUNTER.fetch_add(1, Ordering::Relaxed)
+}
+
+#[inline]
+fn current_thread_id() -> u64 {
+    THREAD_ID.with(|&id| id)
+}
+
 #[cfg(feature = "experimental")]
 pub mod experimental;
 
+#[cfg(feature = "async")]
+mod future;
+
+#[cfg(feature = "async")]
+pub use future::PoolStream;
+
+mod boxed;
+pub use boxed::{BoxedPool, BoxedReusable, BoxedReusableOwned};
+
+mod local;
+pub use local::{LocalPool, LocalReusable};
+
 pub type Stack<T> = Vec<T>;
 
+/// Objects that know how to reset themselves to a clean state.
+///
+/// Implement this for objects that should be cleared before they're handed back out by the
+/// pool, then build the pool with [`Pool::new_recyclable`] to have `recycle` called
+/// automatically whenever an object is returned (on `Drop` or `attach`), rather than having
+/// every caller remember to reset it after pulling.
+pub trait Recyclable {
+    fn recycle(&mut self);
+}
+
 pub struct Pool<T> {
     objects: Mutex<Stack<T>>,
+    recycle: Option<fn(&mut T)>,
+    max_capacity: Option<usize>,
+    #[cfg(feature = "async")]
+    wakers: Mutex<Vec<(u64, std::task::Waker)>>,
+    /// `0` until some thread first calls `try_pull`/`attach`, after which it holds that
+    /// thread's id forever: the thread that happens to touch the pool first becomes its
+    /// owner and is the only one that tries `fast_slot` first. Every other thread still
+    /// falls back to it (see `try_pull`), so an object isn't stranded if the owner thread
+    /// never calls in again (e.g. it exited).
+    owner: AtomicU64,
+    fast_slot_occupied: AtomicBool,
+    fast_slot: UnsafeCell<Option<T>>,
+    /// The pool's total object count (`objects` plus `fast_slot`), tracked independently of
+    /// either so `max_capacity` can be enforced with a single atomic reservation regardless
+    /// of which path an object is taken from or returned through.
+    count: AtomicUsize,
 }
 
+// SAFETY: `fast_slot` is only ever read or written after winning a compare-exchange on
+// `fast_slot_occupied`, which admits at most one writer and one reader at a time, so
+// `Pool<T>` can be shared across threads exactly like `Mutex<Stack<T>>` already requires.
+unsafe impl<T: Send> Sync for Pool<T> {}
+
 impl<T> Pool<T> {
     #[inline]
     pub fn new<F>(cap: usize, mut init: F) -> Pool<T>
@@ -100,33 +183,147 @@ impl<T> Pool<T> {
     {
         Pool {
             objects: Mutex::new((0..cap).map(|_| init()).collect()),
+            recycle: None,
+            max_capacity: None,
+            #[cfg(feature = "async")]
+            wakers: Mutex::new(Vec::new()),
+            owner: AtomicU64::new(0),
+            fast_slot_occupied: AtomicBool::new(false),
+            fast_slot: UnsafeCell::new(None),
+            count: AtomicUsize::new(cap),
+        }
+    }
+
+    /// Like [`Pool::new`], but `attach` (and therefore returning a [`Reusable`]/
+    /// [`ReusableOwned`]) will drop the object instead of pushing it back once the pool
+    /// already holds `max` objects. Use this to put a high-water mark on pools that are fed
+    /// by a `fallback` closure, so a burst of transient allocations doesn't permanently grow
+    /// the pool beyond what's needed.
+    #[inline]
+    pub fn with_max_capacity<F>(cap: usize, max: usize, mut init: F) -> Pool<T>
+    where
+        F: FnMut() -> T,
+    {
+        Pool {
+            objects: Mutex::new((0..cap).map(|_| init()).collect()),
+            recycle: None,
+            max_capacity: Some(max),
+            #[cfg(feature = "async")]
+            wakers: Mutex::new(Vec::new()),
+            owner: AtomicU64::new(0),
+            fast_slot_occupied: AtomicBool::new(false),
+            fast_slot: UnsafeCell::new(None),
+            count: AtomicUsize::new(cap),
         }
     }
 
     #[inline]
     #[must_use]
     pub fn from_vec(v: Vec<T>) -> Pool<T> {
+        let count = v.len();
         Pool {
             objects: Mutex::new(v),
+            recycle: None,
+            max_capacity: None,
+            #[cfg(feature = "async")]
+            wakers: Mutex::new(Vec::new()),
+            owner: AtomicU64::new(0),
+            fast_slot_occupied: AtomicBool::new(false),
+            fast_slot: UnsafeCell::new(None),
+            count: AtomicUsize::new(count),
         }
     }
 
     #[inline]
     pub fn len(&self) -> usize {
-        self.objects.lock().len()
+        self.count.load(Ordering::Acquire)
     }
 
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.objects.lock().is_empty()
+        self.len() == 0
+    }
+
+    /// Claims this thread as the pool's fast-path owner if no thread has yet, and reports
+    /// whether the calling thread is the owner.
+    #[inline]
+    fn is_owner(&self) -> bool {
+        let tid = current_thread_id();
+        let owner = self.owner.load(Ordering::Acquire);
+        if owner == tid {
+            return true;
+        }
+        if owner != 0 {
+            return false;
+        }
+        // First thread to touch the pool claims fast-path ownership; lose the race and
+        // fall back to whichever thread got there first.
+        match self
+            .owner
+            .compare_exchange(0, tid, Ordering::AcqRel, Ordering::Acquire)
+        {
+            Ok(_) => true,
+            Err(owner) => owner == tid,
+        }
+    }
+
+    /// Takes the object out of the fast slot, if one is present. Synchronized purely via
+    /// `fast_slot_occupied`'s compare-exchange, so it's safe to call from any thread, not
+    /// just the owner: an object the owner thread parked here must still be reachable by
+    /// someone else (another thread waiting on [`Pool::pull_async`], or the owner thread
+    /// having since exited), so `try_pull`/`try_pull_owned` fall back to this on any thread
+    /// once `objects` comes up empty.
+    #[inline]
+    fn take_fast(&self) -> Option<T> {
+        if self
+            .fast_slot_occupied
+            .compare_exchange(true, false, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            // SAFETY: we just won the compare-exchange from `true` to `false`, so no other
+            // `take_fast` call can be reading `fast_slot` concurrently.
+            unsafe { (*self.fast_slot.get()).take() }
+        } else {
+            None
+        }
+    }
+
+    /// Stores `t` in the owner-thread fast slot if it's empty, returning `t` back if it was
+    /// already occupied. Must only be called by the owning thread.
+    #[inline]
+    fn put_fast(&self, t: T) -> Option<T> {
+        if self
+            .fast_slot_occupied
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            // SAFETY: see `take_fast`.
+            unsafe {
+                *self.fast_slot.get() = Some(t);
+            }
+            None
+        } else {
+            Some(t)
+        }
     }
 
     #[inline]
     pub fn try_pull(&self) -> Option<Reusable<T>> {
-        self.objects
-            .lock()
-            .pop()
-            .map(|data| Reusable::new(self, data))
+        if self.is_owner() {
+            if let Some(data) = self.take_fast() {
+                self.release(1);
+                return Some(Reusable::new(self, data));
+            }
+        }
+        if let Some(data) = self.objects.lock().pop() {
+            self.release(1);
+            return Some(Reusable::new(self, data));
+        }
+        // Not the owner thread (or the owner raced us and lost), and `objects` is empty:
+        // fall back to the fast slot anyway, so an object it holds is never stranded.
+        let data = self.take_fast()?;
+        self.release(1);
+        Some(Reusable::new(self, data))
     }
 
     #[inline]
@@ -142,10 +339,20 @@ impl<T> Pool<T> {
     /// check on the pool.
     #[inline]
     pub fn try_pull_owned(self: &Arc<Self>) -> Option<ReusableOwned<T>> {
-        self.objects
-            .lock()
-            .pop()
-            .map(|data| ReusableOwned::new(self.clone(), data))
+        if self.is_owner() {
+            if let Some(data) = self.take_fast() {
+                self.release(1);
+                return Some(ReusableOwned::new(self.clone(), data));
+            }
+        }
+        if let Some(data) = self.objects.lock().pop() {
+            self.release(1);
+            return Some(ReusableOwned::new(self.clone(), data));
+        }
+        // See the fallback in `try_pull`.
+        let data = self.take_fast()?;
+        self.release(1);
+        Some(ReusableOwned::new(self.clone(), data))
     }
 
     /// Like pull, but returns an owned reusable wrapper.
@@ -158,16 +365,267 @@ impl<T> Pool<T> {
             .unwrap_or_else(|| ReusableOwned::new(self.clone(), fallback()))
     }
 
+    /// Waits until an object is available instead of allocating a fresh one, unlike
+    /// [`Pool::pull`]. Useful for pools that cap the total number of live objects, where
+    /// callers should back-pressure on return rather than grow the pool.
+    #[cfg(feature = "async")]
+    pub async fn pull_async(&self) -> Reusable<'_, T> {
+        future::PullFuture::new(self).await
+    }
+
+    /// Like [`Pool::pull_async`], but returns an owned reusable wrapper, see
+    /// [`Pool::pull_owned`].
+    #[cfg(feature = "async")]
+    pub async fn pull_owned_async(self: &Arc<Self>) -> ReusableOwned<T> {
+        let data = future::PullFuture::new(self).await.detach().1;
+        ReusableOwned::new(self.clone(), data)
+    }
+
+    /// Returns a [`Stream`](futures_core::Stream) that yields a [`ReusableOwned<T>`] each
+    /// time an object becomes available in the pool.
+    #[cfg(feature = "async")]
+    #[must_use]
+    pub fn stream(self: &Arc<Self>) -> PoolStream<T> {
+        PoolStream::new(self.clone())
+    }
+
+    #[cfg(feature = "async")]
     #[inline]
-    pub fn attach(&self, t: T) {
+    fn wake_one(&self) {
+        let mut wakers = self.wakers.lock();
+        if wakers.is_empty() {
+            return;
+        }
+        let (_, waker) = wakers.remove(0);
+        drop(wakers);
+        waker.wake();
+    }
+
+    /// Atomically reserves up to `n` slots against `max_capacity` via a compare-exchange
+    /// loop on `count`, returning how many were actually granted (fewer than `n`, or `0`, if
+    /// the pool is already full). Unconditional when there's no cap. Pairs with `release`,
+    /// called once an object taken via this reservation actually leaves the pool again, so
+    /// the two stay in sync regardless of whether the object moves through `fast_slot` or
+    /// `objects`.
+    fn reserve(&self, n: usize) -> usize {
+        let Some(max) = self.max_capacity else {
+            self.count.fetch_add(n, Ordering::AcqRel);
+            return n;
+        };
+        let mut current = self.count.load(Ordering::Acquire);
+        loop {
+            let granted = max.saturating_sub(current).min(n);
+            if granted == 0 {
+                return 0;
+            }
+            match self.count.compare_exchange_weak(
+                current,
+                current + granted,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return granted,
+                Err(observed) => current = observed,
+            }
+        }
+    }
+
+    /// Releases `n` slots previously granted by `reserve`.
+    #[inline]
+    fn release(&self, n: usize) {
+        self.count.fetch_sub(n, Ordering::AcqRel);
+    }
+
+    #[inline]
+    pub fn attach(&self, mut t: T) {
+        if let Some(recycle) = self.recycle {
+            recycle(&mut t);
+        }
+        // Reserves the slot against `max_capacity` up front so the check-and-push is
+        // atomic regardless of which path (`fast_slot` or `objects`) ends up taking it.
+        if self.reserve(1) == 0 {
+            return;
+        }
+        if self.is_owner() {
+            if let Some(leftover) = self.put_fast(t) {
+                t = leftover;
+            } else {
+                #[cfg(feature = "async")]
+                self.wake_one();
+                return;
+            }
+        }
         self.objects.lock().push(t);
+        #[cfg(feature = "async")]
+        self.wake_one();
+    }
+
+    /// Returns a [`Puller`] that amortizes the `objects` lock over up to `batch` calls to
+    /// [`Puller::take`], instead of paying for a lock/unlock on every single pull. Best
+    /// suited to hot loops that pull and return many objects in quick succession, e.g.
+    /// `Vec<u8>` buffer churn in a parser.
+    #[inline]
+    #[must_use]
+    pub fn puller(&self, batch: usize) -> Puller<'_, T> {
+        Puller {
+            pool: self,
+            batch,
+            buffer: Vec::with_capacity(batch),
+        }
+    }
+
+    /// Like [`Pool::puller`], but holds on to an [`Arc`] so it doesn't borrow the pool.
+    #[inline]
+    #[must_use]
+    pub fn puller_owned(self: &Arc<Self>, batch: usize) -> PullerOwned<T> {
+        PullerOwned {
+            pool: self.clone(),
+            batch,
+            buffer: Vec::with_capacity(batch),
+        }
+    }
+}
+
+/// A batching handle that refills a local buffer of up to `batch` objects per lock
+/// acquisition, obtained from [`Pool::puller`].
+///
+/// Unlike [`Reusable`], [`Puller::take`] hands objects out by value; return them with
+/// [`Puller::give_back`] to keep reusing the local buffer, or just let them drop (they'll
+/// be lost to the pool, same as dropping a detached object). Any objects still held in the
+/// local buffer when the `Puller` itself is dropped are returned to the pool in one locked
+/// `extend`, up to the pool's `max_capacity`; any beyond that are dropped.
+pub struct Puller<'a, T> {
+    pool: &'a Pool<T>,
+    batch: usize,
+    buffer: Vec<T>,
+}
+
+impl<'a, T> Puller<'a, T> {
+    /// Takes an object from the local buffer, refilling it from the pool under a single
+    /// lock acquisition if it's empty.
+    pub fn take(&mut self) -> Option<T> {
+        take_batched(self.pool, self.batch, &mut self.buffer)
+    }
+
+    /// Returns an object to the local buffer without touching the pool's lock.
+    pub fn give_back(&mut self, t: T) {
+        give_back_batched(self.pool, &mut self.buffer, t);
+    }
+}
+
+impl<'a, T> Drop for Puller<'a, T> {
+    fn drop(&mut self) {
+        return_batch(self.pool, &mut self.buffer);
+    }
+}
+
+/// Like [`Puller`], but holds an owned [`Arc<Pool<T>>`] instead of borrowing it. Obtained
+/// from [`Pool::puller_owned`].
+pub struct PullerOwned<T> {
+    pool: Arc<Pool<T>>,
+    batch: usize,
+    buffer: Vec<T>,
+}
+
+impl<T> PullerOwned<T> {
+    /// See [`Puller::take`].
+    pub fn take(&mut self) -> Option<T> {
+        take_batched(&self.pool, self.batch, &mut self.buffer)
+    }
+
+    /// See [`Puller::give_back`].
+    pub fn give_back(&mut self, t: T) {
+        give_back_batched(&self.pool, &mut self.buffer, t);
+    }
+}
+
+impl<T> Drop for PullerOwned<T> {
+    fn drop(&mut self) {
+        return_batch(&self.pool, &mut self.buffer);
+    }
+}
+
+/// Pops an object from `buffer`, refilling it from `pool.objects` under a single lock
+/// acquisition first if it's empty. Shared by [`Puller::take`] and [`PullerOwned::take`].
+fn take_batched<T>(pool: &Pool<T>, batch: usize, buffer: &mut Vec<T>) -> Option<T> {
+    if buffer.is_empty() {
+        let mut objects = pool.objects.lock();
+        let len = objects.len();
+        let n = batch.min(len);
+        buffer.extend(objects.drain(len - n..));
+        drop(objects);
+        pool.release(n);
+    }
+    buffer.pop()
+}
+
+/// Recycles `t` and pushes it onto `buffer` without touching the pool's lock. Shared by
+/// [`Puller::give_back`] and [`PullerOwned::give_back`].
+fn give_back_batched<T>(pool: &Pool<T>, buffer: &mut Vec<T>, mut t: T) {
+    if let Some(recycle) = pool.recycle {
+        recycle(&mut t);
+    }
+    buffer.push(t);
+}
+
+/// Returns as many of `buffer`'s objects to `pool.objects` as `max_capacity` allows, under
+/// a single lock acquisition, shared by [`Puller`] and [`PullerOwned`]'s `Drop` impls. Any
+/// leftover beyond what `reserve` grants is dropped, same as a single `attach` onto a full
+/// pool.
+fn return_batch<T>(pool: &Pool<T>, buffer: &mut Vec<T>) {
+    if buffer.is_empty() {
+        return;
+    }
+    let room = pool.reserve(buffer.len());
+    if room > 0 {
+        let mut objects = pool.objects.lock();
+        objects.extend(buffer.drain(..room));
+        drop(objects);
+    }
+    buffer.clear();
+    #[cfg(feature = "async")]
+    for _ in 0..room {
+        pool.wake_one();
+    }
+}
+
+impl<T: Recyclable> Pool<T> {
+    /// Like [`Pool::new`], but `t.recycle()` is called on every object before it's pushed
+    /// back into the pool (in [`Reusable`]/[`ReusableOwned`]'s `Drop` impl, or in
+    /// [`Pool::attach`]), instead of leaving reset up to the caller.
+    #[inline]
+    pub fn new_recyclable<F>(cap: usize, mut init: F) -> Pool<T>
+    where
+        F: FnMut() -> T,
+    {
+        Pool {
+            objects: Mutex::new((0..cap).map(|_| init()).collect()),
+            recycle: Some(Recyclable::recycle),
+            max_capacity: None,
+            #[cfg(feature = "async")]
+            wakers: Mutex::new(Vec::new()),
+            owner: AtomicU64::new(0),
+            fast_slot_occupied: AtomicBool::new(false),
+            fast_slot: UnsafeCell::new(None),
+            count: AtomicUsize::new(cap),
+        }
     }
 }
 
 impl<T> FromIterator<T> for Pool<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let objects: Stack<T> = iter.into_iter().collect();
+        let count = objects.len();
         Self {
-            objects: Mutex::new(iter.into_iter().collect()),
+            objects: Mutex::new(objects),
+            recycle: None,
+            max_capacity: None,
+            #[cfg(feature = "async")]
+            wakers: Mutex::new(Vec::new()),
+            owner: AtomicU64::new(0),
+            fast_slot_occupied: AtomicBool::new(false),
+            fast_slot: UnsafeCell::new(None),
+            count: AtomicUsize::new(count),
         }
     }
 }
@@ -276,8 +734,9 @@ impl<T> Drop for ReusableOwned<T> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Pool, Reusable};
+    use crate::{Pool, Reusable, Recyclable};
     use std::mem::drop;
+    use std::sync::Arc;
 
     #[test]
     fn detach() {
@@ -333,4 +792,117 @@ mod tests {
             assert_eq!(object.pop(), Some(i));
         }
     }
+
+    #[test]
+    fn new_recyclable_resets_on_return() {
+        struct Buf(Vec<u8>);
+
+        impl Recyclable for Buf {
+            fn recycle(&mut self) {
+                self.0.clear();
+            }
+        }
+
+        let pool = Pool::new_recyclable(1, || Buf(Vec::new()));
+        {
+            let mut object = pool.try_pull().unwrap();
+            object.0.extend_from_slice(&[1, 2, 3]);
+        }
+
+        let object = pool.try_pull().unwrap();
+        assert!(object.0.is_empty());
+    }
+
+    #[test]
+    fn with_max_capacity_drops_overflow() {
+        let pool = Pool::with_max_capacity(0, 1, Vec::<u8>::new);
+
+        let a = pool.pull(Vec::new);
+        let b = pool.pull(Vec::new);
+        drop(a);
+        drop(b);
+
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn fast_path_owner_and_other_threads_agree() {
+        let pool = Arc::new(Pool::<u32>::new(0, || 0));
+
+        // Claims this thread as the fast-path owner.
+        pool.attach(1);
+        pool.attach(2);
+        assert_eq!(pool.len(), 2);
+
+        // A non-owner thread must still be able to pull/attach through the mutex path,
+        // leaving the fast-path owner's accounting untouched.
+        let other_pool = pool.clone();
+        let value = std::thread::spawn(move || {
+            let object = other_pool.try_pull().unwrap();
+            *object
+        })
+        .join()
+        .unwrap();
+
+        assert!(value == 1 || value == 2);
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[test]
+    fn non_owner_thread_can_reclaim_fast_slot_object() {
+        let pool = Arc::new(Pool::<u32>::new(0, || 0));
+
+        // This thread claims fast-path ownership and parks an object in `fast_slot`.
+        pool.attach(1);
+        assert_eq!(pool.len(), 1);
+
+        // A different thread must still be able to retrieve it — e.g. it's the only one
+        // woken by `pull_async`, or the owner thread has since exited — rather than the
+        // object being stranded forever behind `is_owner()`.
+        let other_pool = pool.clone();
+        let value = std::thread::spawn(move || {
+            let object = other_pool.try_pull().unwrap();
+            *object
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(value, 1);
+        // `object` was dropped at the end of the spawned closure, returning it to the pool.
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn concurrent_attach_never_exceeds_max_capacity() {
+        let pool = Arc::new(Pool::with_max_capacity(0, 4, Vec::<u8>::new));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let pool = pool.clone();
+                std::thread::spawn(move || pool.attach(Vec::new()))
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(pool.len(), 4);
+    }
+
+    #[test]
+    fn puller_batches_under_one_lock_and_returns_leftovers() {
+        let pool = Pool::new(5, Vec::<u8>::new);
+
+        let mut puller = pool.puller(3);
+        let a = puller.take().unwrap();
+        let b = puller.take().unwrap();
+        assert_eq!(pool.len(), 2); // 5 - 3 taken into the puller's local buffer
+
+        puller.give_back(a);
+        drop(b); // lost, same as dropping a detached object
+
+        // Buffer still holds the one object from the batch that wasn't taken, plus `a`.
+        drop(puller);
+        assert_eq!(pool.len(), 4);
+    }
 }
@@ -0,0 +1,150 @@
+//! A single-threaded variant of [`Pool`](crate::Pool), for `!Send`/`!Sync` `T`.
+
+use std::cell::RefCell;
+use std::mem::{forget, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+
+/// Like [`Pool`](crate::Pool), but backed by a [`RefCell`] instead of a `Mutex`, so it only
+/// works from a single thread. Useful when `T` is `!Send`/`!Sync` (e.g. it contains an
+/// [`Rc`](std::rc::Rc)) and therefore can't live in a thread-safe `Pool` at all, or simply
+/// to avoid paying for synchronization you don't need.
+pub struct LocalPool<T> {
+    objects: RefCell<Vec<T>>,
+}
+
+impl<T> LocalPool<T> {
+    #[inline]
+    pub fn new<F>(cap: usize, mut init: F) -> LocalPool<T>
+    where
+        F: FnMut() -> T,
+    {
+        LocalPool {
+            objects: RefCell::new((0..cap).map(|_| init()).collect()),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_vec(v: Vec<T>) -> LocalPool<T> {
+        LocalPool {
+            objects: RefCell::new(v),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.objects.borrow().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.objects.borrow().is_empty()
+    }
+
+    #[inline]
+    pub fn try_pull(&self) -> Option<LocalReusable<'_, T>> {
+        self.objects
+            .borrow_mut()
+            .pop()
+            .map(|data| LocalReusable::new(self, data))
+    }
+
+    #[inline]
+    pub fn pull<F: Fn() -> T>(&self, fallback: F) -> LocalReusable<'_, T> {
+        self.try_pull()
+            .unwrap_or_else(|| LocalReusable::new(self, fallback()))
+    }
+
+    #[inline]
+    pub fn attach(&self, t: T) {
+        self.objects.borrow_mut().push(t);
+    }
+}
+
+pub struct LocalReusable<'a, T> {
+    pool: &'a LocalPool<T>,
+    data: ManuallyDrop<T>,
+}
+
+impl<'a, T> LocalReusable<'a, T> {
+    #[inline]
+    pub fn new(pool: &'a LocalPool<T>, t: T) -> Self {
+        Self {
+            pool,
+            data: ManuallyDrop::new(t),
+        }
+    }
+
+    #[inline]
+    pub fn detach(mut self) -> (&'a LocalPool<T>, T) {
+        let ret = unsafe { (self.pool, self.take()) };
+        forget(self);
+        ret
+    }
+
+    unsafe fn take(&mut self) -> T {
+        ManuallyDrop::take(&mut self.data)
+    }
+}
+
+impl<'a, T> Deref for LocalReusable<'a, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<'a, T> DerefMut for LocalReusable<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+impl<'a, T> Drop for LocalReusable<'a, T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { self.pool.attach(self.take()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LocalPool;
+    use std::mem::drop;
+    use std::rc::Rc;
+
+    #[test]
+    fn pull() {
+        let pool = LocalPool::<Vec<u8>>::new(1, Vec::new);
+
+        let object1 = pool.try_pull();
+        let object2 = pool.try_pull();
+        let object3 = pool.pull(Vec::new);
+
+        assert!(object1.is_some());
+        assert!(object2.is_none());
+        drop(object1);
+        drop(object2);
+        drop(object3);
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[test]
+    fn detach_then_attach() {
+        let pool = LocalPool::new(1, Vec::new);
+        let (pool, mut object) = pool.try_pull().unwrap().detach();
+        object.push(1);
+        pool.attach(object);
+        assert_eq!(pool.try_pull().unwrap()[0], 1);
+    }
+
+    #[test]
+    fn works_with_non_send_objects() {
+        let pool = LocalPool::new(1, || Rc::new(0));
+        let object = pool.try_pull().unwrap();
+        assert_eq!(**object, 0);
+    }
+}